@@ -1,6 +1,7 @@
 use std::{
     collections::HashSet,
     fmt::{Display, Formatter},
+    str::FromStr,
 };
 
 const DIRECTIONS: [(isize, isize); 8] = [
@@ -22,15 +23,19 @@ pub enum CellState {
     Danger(u8),
 }
 
-#[derive(PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct Position {
     pub col: usize,
     pub row: usize,
 }
 
+#[derive(Debug, PartialEq)]
 pub struct Board {
     pub states: Vec<Vec<CellState>>,
     pub bomb_positions: HashSet<Position>,
+    // Total number of bombs on the board. The solver relies on this count
+    // rather than `bomb_positions`, which it is not allowed to read.
+    pub num_bombs: usize,
 }
 
 pub struct BoardOptions {
@@ -66,13 +71,15 @@ impl Position {
 
 impl Board {
     pub fn new(options: &BoardOptions) -> Self {
+        let bomb_positions = if options.bomb_probability == 0.0 {
+            HashSet::new()
+        } else {
+            Self::generate_random_bomb_positions(options)
+        };
         Self {
             states: vec![vec![CellState::Unknown; options.num_cols]; options.num_rows],
-            bomb_positions: if options.bomb_probability == 0.0 {
-                HashSet::new()
-            } else {
-                Self::generate_random_bomb_positions(options)
-            },
+            num_bombs: bomb_positions.len(),
+            bomb_positions,
         }
     }
 
@@ -147,6 +154,52 @@ impl Display for Board {
     }
 }
 
+// Error returned when a grid string cannot be parsed into a `Board`.
+#[derive(Debug, PartialEq)]
+pub enum ParseBoardError {
+    UnevenRows,
+    UnknownSymbol(char),
+}
+
+impl FromStr for Board {
+    type Err = ParseBoardError;
+
+    // Reads the grid produced by `Display`: `-` is `Unknown`, a space is
+    // `Empty`, `X` is a `Bomb`, and a digit `1`-`8` is `Danger(n)`. Each cell
+    // symbol is followed by a separating space, so a row holds twice as many
+    // characters as it has columns.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut states: Vec<Vec<CellState>> = Vec::new();
+        let mut bomb_positions = HashSet::new();
+        for (row, line) in s.lines().enumerate() {
+            let symbols = line.chars().step_by(2).collect::<Vec<char>>();
+            let mut row_states = Vec::with_capacity(symbols.len());
+            for (col, symbol) in symbols.into_iter().enumerate() {
+                let state = match symbol {
+                    '-' => CellState::Unknown,
+                    ' ' => CellState::Empty,
+                    'X' => {
+                        bomb_positions.insert(Position::new(row, col));
+                        CellState::Bomb
+                    }
+                    '1'..='8' => CellState::Danger(symbol as u8 - b'0'),
+                    other => return Err(ParseBoardError::UnknownSymbol(other)),
+                };
+                row_states.push(state);
+            }
+            states.push(row_states);
+        }
+        if states.len() > 1 && states.iter().any(|r| r.len() != states[0].len()) {
+            return Err(ParseBoardError::UnevenRows);
+        }
+        Ok(Self {
+            states,
+            num_bombs: bomb_positions.len(),
+            bomb_positions,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -189,6 +242,7 @@ mod tests {
                 vec![E, E, E, E, E],
             ]),
             bomb_positions: HashSet::new(),
+            num_bombs: 0,
         };
         assert_eq!(
             format!("{board}"),
@@ -204,6 +258,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_round_trip() {
+        let board = Board {
+            states: to_cell_state_grid(vec![
+                vec![1, 2, 2, 1, E],
+                vec![1, B, U, 1, E],
+                vec![1, 2, 2, 1, E],
+                vec![E, E, E, E, E],
+                vec![E, E, E, E, E],
+            ]),
+            bomb_positions: HashSet::from_iter(vec![Position::new(1, 1)]),
+            num_bombs: 1,
+        };
+        let parsed = board.to_string().parse::<Board>().unwrap();
+        assert_eq!(parsed.states, board.states);
+        assert_eq!(parsed.bomb_positions, board.bomb_positions);
+    }
+
+    #[test]
+    fn test_parse_unknown_symbol() {
+        assert_eq!(
+            "1 ? ".parse::<Board>(),
+            Err(ParseBoardError::UnknownSymbol('?'))
+        );
+    }
+
     #[test]
     fn test_reveal_recurses() {
         let mut board = Board {
@@ -215,6 +295,7 @@ mod tests {
                 vec![U, U, U, U, U],
             ]),
             bomb_positions: HashSet::from_iter(vec![Position::new(2, 2)].into_iter()),
+            num_bombs: 1,
         };
         assert_eq!(board.reveal_cell(&Position::new(0, 0)), CellState::Empty);
         assert_eq!(
@@ -240,6 +321,7 @@ mod tests {
                 vec![U, U, U, U, U],
             ]),
             bomb_positions: HashSet::from_iter(vec![Position::new(2, 2)].into_iter()),
+            num_bombs: 1,
         };
         assert_eq!(board.reveal_cell(&Position::new(2, 2)), CellState::Bomb);
         assert_eq!(
@@ -265,6 +347,7 @@ mod tests {
                 vec![U, U, U, U, U],
             ]),
             bomb_positions: HashSet::from_iter(vec![Position::new(2, 2)].into_iter()),
+            num_bombs: 1,
         };
         assert_eq!(
             board.reveal_cell(&Position::new(1, 2)),
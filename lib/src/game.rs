@@ -0,0 +1,157 @@
+use std::collections::HashSet;
+
+use crate::board::{Board, CellState, Position};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GameStatus {
+    Playing,
+    Won,
+    Lost,
+}
+
+// A playable minesweeper game: a `Board` plus the player's flags and the
+// current win/loss status.
+pub struct Game {
+    pub board: Board,
+    pub flags: HashSet<Position>,
+    pub status: GameStatus,
+    // Whether a cell has yet to be revealed, used to guarantee the opening
+    // click is never a bomb.
+    first_reveal: bool,
+}
+
+impl Game {
+    pub fn new(board: Board) -> Self {
+        Self {
+            board,
+            flags: HashSet::new(),
+            status: GameStatus::Playing,
+            first_reveal: true,
+        }
+    }
+
+    // Marks an unrevealed cell with a flag.
+    pub fn flag(&mut self, pos: Position) {
+        if self.board.states[pos.row][pos.col] == CellState::Unknown {
+            self.flags.insert(pos);
+        }
+    }
+
+    // Removes a flag from a cell.
+    pub fn unflag(&mut self, pos: Position) {
+        self.flags.remove(&pos);
+    }
+
+    // Reveals a cell, transitioning to `Lost` if it holds a bomb and to `Won`
+    // once every non-bomb cell has been revealed. The first reveal of the game
+    // is always safe: a bomb under the opening click is relocated first.
+    pub fn reveal(&mut self, pos: Position) -> GameStatus {
+        if self.status != GameStatus::Playing {
+            return self.status;
+        }
+        self.flags.remove(&pos);
+
+        if self.first_reveal {
+            self.first_reveal = false;
+            if self.board.bomb_positions.contains(&pos) {
+                self.relocate_bomb(&pos);
+            }
+        }
+
+        if self.board.reveal_cell(&pos) == CellState::Bomb {
+            self.status = GameStatus::Lost;
+        } else if self.has_won() {
+            self.status = GameStatus::Won;
+        }
+        self.status
+    }
+
+    // Moves the bomb under `pos` to the first free cell, keeping the total
+    // bomb count unchanged.
+    fn relocate_bomb(&mut self, pos: &Position) {
+        let free = (0..self.board.states.len()).find_map(|row| {
+            (0..self.board.states[row].len()).find_map(|col| {
+                let candidate = Position::new(row, col);
+                if candidate != *pos && !self.board.bomb_positions.contains(&candidate) {
+                    Some(candidate)
+                } else {
+                    None
+                }
+            })
+        });
+        if let Some(candidate) = free {
+            self.board.bomb_positions.remove(pos);
+            self.board.bomb_positions.insert(candidate);
+        }
+    }
+
+    // The game is won once every cell that is not a bomb has been revealed.
+    fn has_won(&self) -> bool {
+        self.board
+            .states
+            .iter()
+            .enumerate()
+            .all(|(row, row_vec)| {
+                row_vec.iter().enumerate().all(|(col, state)| {
+                    self.board.bomb_positions.contains(&Position::new(row, col))
+                        || *state != CellState::Unknown
+                })
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fresh_board(bombs: Vec<Position>) -> Board {
+        Board {
+            states: vec![vec![CellState::Unknown; 5]; 5],
+            num_bombs: bombs.len(),
+            bomb_positions: HashSet::from_iter(bombs),
+        }
+    }
+
+    #[test]
+    fn test_flag_and_unflag() {
+        let mut game = Game::new(fresh_board(vec![Position::new(2, 2)]));
+        game.flag(Position::new(0, 0));
+        assert!(game.flags.contains(&Position::new(0, 0)));
+        game.unflag(Position::new(0, 0));
+        assert!(game.flags.is_empty());
+    }
+
+    #[test]
+    fn test_first_click_never_loses() {
+        let mut game = Game::new(fresh_board(vec![Position::new(0, 0)]));
+        assert_eq!(game.reveal(Position::new(0, 0)), GameStatus::Playing);
+        assert!(!game.board.bomb_positions.contains(&Position::new(0, 0)));
+        assert_eq!(game.board.num_bombs, 1);
+    }
+
+    #[test]
+    fn test_revealing_bomb_loses() {
+        let mut game = Game::new(fresh_board(vec![Position::new(0, 0), Position::new(4, 4)]));
+        // Spend the opening-click protection on a numbered cell next to a bomb
+        // so the reveal does not flood and win the board outright.
+        assert_eq!(game.reveal(Position::new(1, 1)), GameStatus::Playing);
+        assert_eq!(game.reveal(Position::new(0, 0)), GameStatus::Lost);
+    }
+
+    #[test]
+    fn test_clearing_all_safe_cells_wins() {
+        let mut game = Game::new(fresh_board(vec![Position::new(0, 0)]));
+        game.reveal(Position::new(4, 4));
+        for row in 0..5 {
+            for col in 0..5 {
+                let pos = Position::new(row, col);
+                if game.board.states[row][col] == CellState::Unknown
+                    && !game.board.bomb_positions.contains(&pos)
+                {
+                    game.reveal(pos);
+                }
+            }
+        }
+        assert_eq!(game.status, GameStatus::Won);
+    }
+}
@@ -0,0 +1,3 @@
+pub mod board;
+pub mod game;
+pub mod solver;
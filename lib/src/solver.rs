@@ -1,5 +1,41 @@
+use std::collections::{HashMap, HashSet};
+use std::time::Instant;
+
 use crate::board::{Board, CellState, Position};
 
+// Above this many frontier cells, exact enumeration becomes infeasible and
+// `rank_positions_estimated` switches to Monte-Carlo sampling.
+const EXACT_FRONTIER_LIMIT: usize = 20;
+
+// Default number of sampled configurations for the Monte-Carlo estimator.
+const DEFAULT_SAMPLE_COUNT: usize = 10_000;
+
+// A seedable xorshift PRNG, kept deliberately tiny so sampled rankings are
+// fast and reproducible from a given seed.
+struct Xorshift {
+    state: u64,
+}
+
+impl Xorshift {
+    fn new(seed: u64) -> Self {
+        // A zero state is a fixed point of xorshift, so nudge it off zero.
+        Self {
+            state: if seed == 0 { 0x9e3779b97f4a7c15 } else { seed },
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state << 7;
+        self.state ^= self.state >> 9;
+        self.state
+    }
+
+    // A value in `0..range`.
+    fn below(&mut self, range: usize) -> usize {
+        (self.next_u64() % range as u64) as usize
+    }
+}
+
 pub struct PositionBombProbability {
     pub position: Position,
     pub probability: f64,
@@ -14,6 +50,38 @@ impl PositionBombProbability {
     }
 }
 
+// A single "exactly `bombs` of these `cells` are mines" constraint, derived
+// from a revealed `Danger` cell's still-`Unknown` neighbors.
+#[derive(Clone)]
+pub struct Constraint {
+    pub cells: Vec<Position>,
+    pub bombs: u8,
+}
+
+// An independent slice of the constraint graph: a set of frontier cells and
+// the constraints over them that share no cells with any other group, so it
+// can be solved in isolation.
+pub struct ConstraintGroup {
+    pub cells: Vec<Position>,
+    pub constraints: Vec<Constraint>,
+}
+
+// The result of enumerating a group: the number of satisfying assignments and,
+// for each cell in `ConstraintGroup::cells`, how many of them marked it a bomb.
+pub struct GroupSolution {
+    pub total: usize,
+    pub bomb_tallies: Vec<usize>,
+}
+
+// Key identifying structurally identical groups: the multiset of constraints,
+// each over its cells' coordinates normalized by translation to the group's
+// top-left, so the same local pattern anywhere on the board hashes alike.
+type CanonicalKey = Vec<(Vec<(usize, usize)>, u8)>;
+
+// A solved group's satisfying-assignment total paired with its per-coordinate
+// bomb tallies, keyed by translation-normalized coordinate.
+type GroupCount = (usize, HashMap<(usize, usize), usize>);
+
 pub fn rank_positions(board: &Board) -> Vec<PositionBombProbability> {
     if board
         .states
@@ -35,7 +103,546 @@ pub fn rank_positions(board: &Board) -> Vec<PositionBombProbability> {
             })
             .collect::<Vec<PositionBombProbability>>();
     }
-    panic!("unimplemented")
+
+    let groups = constraint_groups(board);
+    let solutions = solve_groups(&groups);
+
+    // Probability of each frontier cell, plus the expected number of bombs the
+    // frontier accounts for (so the remainder can be spread over the outside).
+    let mut probabilities: HashMap<Position, f64> = HashMap::new();
+    let mut expected_frontier_bombs = 0.0;
+    for (group, solution) in groups.iter().zip(solutions.iter()) {
+        if solution.total == 0 {
+            continue;
+        }
+        for (i, cell) in group.cells.iter().enumerate() {
+            let p = solution.bomb_tallies[i] as f64 / solution.total as f64;
+            probabilities.insert(*cell, p);
+            expected_frontier_bombs += p;
+        }
+    }
+
+    // Unknown cells with no adjacent number share whatever bomb mass the
+    // frontier did not claim, spread uniformly.
+    let unknowns = unknown_positions(board);
+    let outside: Vec<Position> = unknowns
+        .iter()
+        .filter(|pos| !probabilities.contains_key(pos))
+        .copied()
+        .collect();
+    if !outside.is_empty() {
+        let remaining = (board.num_bombs as f64 - expected_frontier_bombs).max(0.0);
+        let outside_probability = (remaining / outside.len() as f64).min(1.0);
+        for pos in &outside {
+            probabilities.insert(*pos, outside_probability);
+        }
+    }
+
+    unknowns
+        .into_iter()
+        .map(|pos| {
+            let probability = probabilities.get(&pos).copied().unwrap_or(0.0);
+            PositionBombProbability::new(pos, probability)
+        })
+        .collect::<Vec<PositionBombProbability>>()
+}
+
+// Ranks positions, choosing the Monte-Carlo estimator when the frontier is too
+// large for exact enumeration and falling back to the exact solver otherwise.
+pub fn rank_positions_estimated(board: &Board) -> Vec<PositionBombProbability> {
+    let (_, frontier) = build_frontier(board);
+    if frontier.len() > EXACT_FRONTIER_LIMIT {
+        rank_positions_monte_carlo(board, DEFAULT_SAMPLE_COUNT, None, 1)
+    } else {
+        rank_positions(board)
+    }
+}
+
+// Estimates per-cell bomb probabilities by sampling valid configurations
+// instead of enumerating them. Work is bounded by `samples` and, when given, a
+// wall-clock `deadline`; `seed` makes the sampling reproducible.
+pub fn rank_positions_monte_carlo(
+    board: &Board,
+    samples: usize,
+    deadline: Option<Instant>,
+    seed: u64,
+) -> Vec<PositionBombProbability> {
+    let (cells, constraints) = build_frontier(board);
+    let index: HashMap<Position, usize> = cells
+        .iter()
+        .enumerate()
+        .map(|(i, cell)| (*cell, i))
+        .collect();
+    let local: Vec<(Vec<usize>, u8)> = constraints
+        .iter()
+        .map(|c| (c.cells.iter().map(|p| index[p]).collect(), c.bombs))
+        .collect();
+
+    let mut rng = Xorshift::new(seed);
+    let mut tally = vec![0usize; cells.len()];
+    let mut accepted = 0usize;
+    let mut frontier_bomb_total = 0usize;
+    for _ in 0..samples {
+        if deadline.is_some_and(|d| Instant::now() >= d) {
+            break;
+        }
+        if let Some(assignment) = sample_assignment(cells.len(), &local, &mut rng) {
+            accepted += 1;
+            for (i, placed) in assignment.iter().enumerate() {
+                if *placed {
+                    tally[i] += 1;
+                    frontier_bomb_total += 1;
+                }
+            }
+        }
+    }
+
+    let mut probabilities: HashMap<Position, f64> = HashMap::new();
+    let mut expected_frontier_bombs = 0.0;
+    if accepted > 0 {
+        expected_frontier_bombs = frontier_bomb_total as f64 / accepted as f64;
+        for (i, cell) in cells.iter().enumerate() {
+            probabilities.insert(*cell, tally[i] as f64 / accepted as f64);
+        }
+    }
+
+    let unknowns = unknown_positions(board);
+    let outside: Vec<Position> = unknowns
+        .iter()
+        .filter(|pos| !probabilities.contains_key(pos))
+        .copied()
+        .collect();
+    if !outside.is_empty() {
+        let remaining = (board.num_bombs as f64 - expected_frontier_bombs).max(0.0);
+        let outside_probability = (remaining / outside.len() as f64).min(1.0);
+        for pos in &outside {
+            probabilities.insert(*pos, outside_probability);
+        }
+    }
+
+    unknowns
+        .into_iter()
+        .map(|pos| {
+            PositionBombProbability::new(pos, probabilities.get(&pos).copied().unwrap_or(0.0))
+        })
+        .collect::<Vec<PositionBombProbability>>()
+}
+
+// The frontier cells (unknowns adjacent to a number) and the constraints over
+// them, both derived from the board's `Danger` cells.
+fn build_frontier(board: &Board) -> (Vec<Position>, Vec<Constraint>) {
+    let constraints = gather_constraints(board);
+    let mut cells: Vec<Position> = Vec::new();
+    let mut seen: HashSet<Position> = HashSet::new();
+    for constraint in &constraints {
+        for cell in &constraint.cells {
+            if seen.insert(*cell) {
+                cells.push(*cell);
+            }
+        }
+    }
+    (cells, constraints)
+}
+
+// Produces one random constraint-satisfying bomb assignment over the frontier
+// by filling cells in a random order and backtracking whenever a constraint
+// becomes unsatisfiable. Returns `None` only when the constraints admit no
+// solution at all.
+fn sample_assignment(
+    len: usize,
+    constraints: &[(Vec<usize>, u8)],
+    rng: &mut Xorshift,
+) -> Option<Vec<bool>> {
+    let mut order: Vec<usize> = (0..len).collect();
+    shuffle(&mut order, rng);
+    let mut assignment: Vec<Option<bool>> = vec![None; len];
+    if fill(&order, 0, constraints, &mut assignment, rng) {
+        Some(assignment.into_iter().map(|v| v.unwrap_or(false)).collect())
+    } else {
+        None
+    }
+}
+
+fn fill(
+    order: &[usize],
+    depth: usize,
+    constraints: &[(Vec<usize>, u8)],
+    assignment: &mut [Option<bool>],
+    rng: &mut Xorshift,
+) -> bool {
+    if depth == order.len() {
+        return true;
+    }
+    let cell = order[depth];
+    let first = rng.below(2) == 1;
+    for value in [first, !first] {
+        assignment[cell] = Some(value);
+        if partial_feasible(constraints, assignment)
+            && fill(order, depth + 1, constraints, assignment, rng)
+        {
+            return true;
+        }
+    }
+    assignment[cell] = None;
+    false
+}
+
+// A partial assignment is feasible when no constraint has overshot its target
+// and every constraint can still reach it with its undecided cells.
+fn partial_feasible(constraints: &[(Vec<usize>, u8)], assignment: &[Option<bool>]) -> bool {
+    for (cells, target) in constraints {
+        let mut placed = 0u8;
+        let mut undecided = 0u8;
+        for &cell in cells {
+            match assignment[cell] {
+                Some(true) => placed += 1,
+                Some(false) => {}
+                None => undecided += 1,
+            }
+        }
+        if placed > *target || placed + undecided < *target {
+            return false;
+        }
+    }
+    true
+}
+
+// Fisher-Yates shuffle driven by the xorshift PRNG.
+fn shuffle(items: &mut [usize], rng: &mut Xorshift) {
+    for i in (1..items.len()).rev() {
+        items.swap(i, rng.below(i + 1));
+    }
+}
+
+// Deduces the cells that are provably bombs and the cells that are provably
+// safe without resorting to probabilities. Each `Danger` cell's unknown
+// neighbors form a constrained set; a constraint whose target equals its cell
+// count makes every cell a mine, a zero target makes every cell safe, and
+// subset-subtraction between constraints derives further certainties until no
+// more can be drawn.
+pub fn deduce(board: &Board) -> (HashSet<Position>, HashSet<Position>) {
+    let mut bombs: HashSet<Position> = HashSet::new();
+    let mut safe: HashSet<Position> = HashSet::new();
+    let mut constraints: Vec<(HashSet<Position>, u8)> = gather_constraints(board)
+        .into_iter()
+        .map(|c| (c.cells.into_iter().collect::<HashSet<Position>>(), c.bombs))
+        .collect();
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+
+        // Resolve any constraint that is fully determined, recording its cells
+        // and erasing them from the remaining constraints.
+        let mut i = 0;
+        while i < constraints.len() {
+            let (cells, target) = &constraints[i];
+            if *target == 0 {
+                let resolved = cells.clone();
+                safe.extend(resolved.iter().copied());
+                remove_cells(&mut constraints, &resolved, false);
+                changed = true;
+            } else if *target as usize == cells.len() {
+                let resolved = cells.clone();
+                bombs.extend(resolved.iter().copied());
+                remove_cells(&mut constraints, &resolved, true);
+                changed = true;
+            } else {
+                i += 1;
+            }
+        }
+
+        // Subset subtraction: when one constraint's cells are contained in
+        // another's, the difference is itself a constraint.
+        for a in 0..constraints.len() {
+            for b in 0..constraints.len() {
+                if a == b {
+                    continue;
+                }
+                let (sub_cells, sub_target) = &constraints[a];
+                let (sup_cells, sup_target) = &constraints[b];
+                if sub_cells.is_empty()
+                    || sub_cells.len() >= sup_cells.len()
+                    || !sub_cells.is_subset(sup_cells)
+                {
+                    continue;
+                }
+                let difference: HashSet<Position> =
+                    sup_cells.difference(sub_cells).copied().collect();
+                let new_target = sup_target.saturating_sub(*sub_target);
+                if constraints[b].0 != difference {
+                    constraints[b] = (difference, new_target);
+                    changed = true;
+                }
+            }
+        }
+    }
+
+    (bombs, safe)
+}
+
+// Drops `resolved` cells from every constraint, decrementing targets when the
+// removed cells were mines.
+fn remove_cells(
+    constraints: &mut Vec<(HashSet<Position>, u8)>,
+    resolved: &HashSet<Position>,
+    were_bombs: bool,
+) {
+    for (cells, target) in constraints.iter_mut() {
+        let removed = cells.intersection(resolved).count() as u8;
+        if removed == 0 {
+            continue;
+        }
+        cells.retain(|cell| !resolved.contains(cell));
+        if were_bombs {
+            *target -= removed;
+        }
+    }
+    constraints.retain(|(cells, _)| !cells.is_empty());
+}
+
+// Every still-`Unknown` position on the board, in row-major order.
+fn unknown_positions(board: &Board) -> Vec<Position> {
+    board
+        .states
+        .iter()
+        .enumerate()
+        .flat_map(|(row, row_vec)| {
+            row_vec
+                .iter()
+                .enumerate()
+                .filter_map(move |(col, state)| {
+                    if *state == CellState::Unknown {
+                        Some(Position::new(row, col))
+                    } else {
+                        None
+                    }
+                })
+        })
+        .collect::<Vec<Position>>()
+}
+
+// One constraint per revealed `Danger` cell, over its `Unknown` neighbors.
+fn gather_constraints(board: &Board) -> Vec<Constraint> {
+    let mut constraints = Vec::new();
+    for (row, row_vec) in board.states.iter().enumerate() {
+        for (col, state) in row_vec.iter().enumerate() {
+            let CellState::Danger(n) = state else {
+                continue;
+            };
+            let pos = Position::new(row, col);
+            let surrounding = pos.surrounding(board);
+            let unknowns = surrounding
+                .iter()
+                .filter(|p| board.states[p.row][p.col] == CellState::Unknown)
+                .copied()
+                .collect::<Vec<Position>>();
+            if unknowns.is_empty() {
+                continue;
+            }
+            // Any neighbor already uncovered as a bomb is subtracted from the
+            // count the unknown neighbors must still satisfy.
+            let known_bombs = surrounding
+                .iter()
+                .filter(|p| board.states[p.row][p.col] == CellState::Bomb)
+                .count() as u8;
+            constraints.push(Constraint {
+                cells: unknowns,
+                bombs: n.saturating_sub(known_bombs),
+            });
+        }
+    }
+    constraints
+}
+
+// Decomposes the board's frontier into independent `ConstraintGroup`s: two
+// constraints land in the same group when they share a cell, so distinct
+// groups can be solved in isolation.
+pub fn constraint_groups(board: &Board) -> Vec<ConstraintGroup> {
+    let constraints = gather_constraints(board);
+    let mut parent: Vec<usize> = (0..constraints.len()).collect();
+    let mut cell_owner: HashMap<Position, usize> = HashMap::new();
+    for (i, constraint) in constraints.iter().enumerate() {
+        for cell in &constraint.cells {
+            match cell_owner.get(cell) {
+                Some(&j) => union(&mut parent, i, j),
+                None => {
+                    cell_owner.insert(*cell, i);
+                }
+            }
+        }
+    }
+
+    let mut grouped: HashMap<usize, ConstraintGroup> = HashMap::new();
+    for (i, constraint) in constraints.into_iter().enumerate() {
+        let root = find(&mut parent, i);
+        grouped
+            .entry(root)
+            .or_insert_with(|| ConstraintGroup {
+                cells: Vec::new(),
+                constraints: Vec::new(),
+            })
+            .constraints
+            .push(constraint);
+    }
+
+    grouped
+        .into_values()
+        .map(|mut group| {
+            let mut seen: HashSet<Position> = HashSet::new();
+            for constraint in &group.constraints {
+                for cell in &constraint.cells {
+                    if seen.insert(*cell) {
+                        group.cells.push(*cell);
+                    }
+                }
+            }
+            group
+        })
+        .collect::<Vec<ConstraintGroup>>()
+}
+
+fn find(parent: &mut [usize], i: usize) -> usize {
+    if parent[i] != i {
+        parent[i] = find(parent, parent[i]);
+    }
+    parent[i]
+}
+
+fn union(parent: &mut [usize], a: usize, b: usize) {
+    let ra = find(parent, a);
+    let rb = find(parent, b);
+    if ra != rb {
+        parent[ra] = rb;
+    }
+}
+
+// Solves every group, memoizing by canonical shape so structurally identical
+// local patterns — ubiquitous in minesweeper — are enumerated only once per
+// rank call and reused across positions.
+pub fn solve_groups(groups: &[ConstraintGroup]) -> Vec<GroupSolution> {
+    let mut cache: HashMap<CanonicalKey, GroupCount> = HashMap::new();
+    groups
+        .iter()
+        .map(|group| {
+            let (key, coords) = canonicalize(group);
+            let (total, tallies) = cache
+                .entry(key.clone())
+                .or_insert_with(|| enumerate_canonical(&coords, &key));
+            let bomb_tallies = coords
+                .iter()
+                .map(|coord| tallies.get(coord).copied().unwrap_or(0))
+                .collect::<Vec<usize>>();
+            GroupSolution {
+                total: *total,
+                bomb_tallies,
+            }
+        })
+        .collect::<Vec<GroupSolution>>()
+}
+
+// Builds a group's canonical key — constraints over translation-normalized
+// cell coordinates — alongside the normalized coordinate of each group cell in
+// `ConstraintGroup::cells` order.
+fn canonicalize(group: &ConstraintGroup) -> (CanonicalKey, Vec<(usize, usize)>) {
+    let min_row = group.cells.iter().map(|c| c.row).min().unwrap_or(0);
+    let min_col = group.cells.iter().map(|c| c.col).min().unwrap_or(0);
+    let norm = |p: &Position| (p.row - min_row, p.col - min_col);
+
+    let mut key: CanonicalKey = group
+        .constraints
+        .iter()
+        .map(|c| {
+            let mut cells = c.cells.iter().map(norm).collect::<Vec<(usize, usize)>>();
+            cells.sort_unstable();
+            (cells, c.bombs)
+        })
+        .collect();
+    key.sort_unstable();
+
+    let coords = group.cells.iter().map(norm).collect::<Vec<(usize, usize)>>();
+    (key, coords)
+}
+
+// Enumerates every satisfying assignment of a canonical group, keyed by its
+// translation-normalized coordinates, returning the satisfying-assignment
+// total and the per-coordinate bomb tallies.
+fn enumerate_canonical(coords: &[(usize, usize)], key: &CanonicalKey) -> GroupCount {
+    let index: HashMap<(usize, usize), usize> = coords
+        .iter()
+        .enumerate()
+        .map(|(i, coord)| (*coord, i))
+        .collect();
+    let local: Vec<(Vec<usize>, u8)> = key
+        .iter()
+        .map(|(cells, bombs)| {
+            (
+                cells.iter().map(|coord| index[coord]).collect::<Vec<usize>>(),
+                *bombs,
+            )
+        })
+        .collect();
+
+    let mut total = 0usize;
+    let mut tally = vec![0usize; coords.len()];
+    let mut assignment = vec![false; coords.len()];
+    backtrack(0, &local, &mut assignment, &mut total, &mut tally);
+
+    let tallies = coords
+        .iter()
+        .enumerate()
+        .map(|(i, coord)| (*coord, tally[i]))
+        .collect::<HashMap<(usize, usize), usize>>();
+    (total, tallies)
+}
+
+fn backtrack(
+    depth: usize,
+    constraints: &[(Vec<usize>, u8)],
+    assignment: &mut [bool],
+    total: &mut usize,
+    tally: &mut [usize],
+) {
+    if depth == assignment.len() {
+        *total += 1;
+        for (i, placed) in assignment.iter().enumerate() {
+            if *placed {
+                tally[i] += 1;
+            }
+        }
+        return;
+    }
+
+    for value in [false, true] {
+        assignment[depth] = value;
+        if feasible(depth + 1, constraints, assignment) {
+            backtrack(depth + 1, constraints, assignment, total, tally);
+        }
+    }
+    assignment[depth] = false;
+}
+
+// A partial assignment (cells `0..assigned` fixed) is feasible when no
+// constraint has already overshot its target and every constraint can still
+// reach it with the cells it has left.
+fn feasible(assigned: usize, constraints: &[(Vec<usize>, u8)], assignment: &[bool]) -> bool {
+    for (cells, target) in constraints {
+        let mut placed = 0u8;
+        let mut undecided = 0u8;
+        for &cell in cells {
+            if cell < assigned {
+                if assignment[cell] {
+                    placed += 1;
+                }
+            } else {
+                undecided += 1;
+            }
+        }
+        if placed > *target || placed + undecided < *target {
+            return false;
+        }
+    }
+    true
 }
 
 #[cfg(test)]
@@ -44,11 +651,20 @@ mod tests {
 
     use super::*;
 
+    fn probability_at(ranked: &[PositionBombProbability], pos: Position) -> f64 {
+        ranked
+            .iter()
+            .find(|r| r.position == pos)
+            .expect("position should be ranked")
+            .probability
+    }
+
     #[test]
     fn test_fresh_board_ranking() {
         let board = Board {
             states: vec![vec![CellState::Unknown; 3]; 3],
             bomb_positions: HashSet::new(),
+            num_bombs: 0,
         };
         let positions_ranked = rank_positions(&board);
         let expected = 1.0 / 9.0;
@@ -58,4 +674,120 @@ mod tests {
                 .all(|position_ranked| position_ranked.probability == expected)
         );
     }
+
+    #[test]
+    fn test_single_one_splits_evenly() {
+        // A lone `1` flanked by two unknowns: each is equally likely the bomb.
+        let board = Board {
+            states: vec![vec![
+                CellState::Unknown,
+                CellState::Danger(1),
+                CellState::Unknown,
+            ]],
+            bomb_positions: HashSet::new(),
+            num_bombs: 1,
+        };
+        let ranked = rank_positions(&board);
+        assert_eq!(ranked.len(), 2);
+        assert_eq!(probability_at(&ranked, Position::new(0, 0)), 0.5);
+        assert_eq!(probability_at(&ranked, Position::new(0, 2)), 0.5);
+    }
+
+    #[test]
+    fn test_monte_carlo_is_reproducible() {
+        let board = Board {
+            states: vec![vec![
+                CellState::Unknown,
+                CellState::Danger(1),
+                CellState::Unknown,
+            ]],
+            bomb_positions: HashSet::new(),
+            num_bombs: 1,
+        };
+        let first = rank_positions_monte_carlo(&board, 500, None, 42);
+        let second = rank_positions_monte_carlo(&board, 500, None, 42);
+        for (a, b) in first.iter().zip(second.iter()) {
+            assert_eq!(a.position, b.position);
+            assert_eq!(a.probability, b.probability);
+        }
+        // Each flanking cell is the bomb about half the time.
+        let left = probability_at(&first, Position::new(0, 0));
+        assert!((left - 0.5).abs() < 0.1, "left probability was {left}");
+    }
+
+    #[test]
+    fn test_constraint_groups_are_independent() {
+        // Two `1`s separated by an empty cell form two independent groups.
+        let board = Board {
+            states: vec![vec![
+                CellState::Unknown,
+                CellState::Danger(1),
+                CellState::Unknown,
+                CellState::Empty,
+                CellState::Unknown,
+                CellState::Danger(1),
+                CellState::Unknown,
+            ]],
+            bomb_positions: HashSet::new(),
+            num_bombs: 2,
+        };
+        let groups = constraint_groups(&board);
+        assert_eq!(groups.len(), 2);
+        let solutions = solve_groups(&groups);
+        // Each "1 flanked by two unknowns" group has exactly two solutions.
+        assert!(solutions.iter().all(|s| s.total == 2));
+        assert!(solutions
+            .iter()
+            .all(|s| s.bomb_tallies.iter().all(|&t| t == 1)));
+    }
+
+    #[test]
+    fn test_deduce_certain_mine() {
+        // A `1` with a single unknown neighbor pins that neighbor as a mine.
+        let board = Board {
+            states: vec![vec![CellState::Danger(1), CellState::Unknown]],
+            bomb_positions: HashSet::new(),
+            num_bombs: 1,
+        };
+        let (bombs, safe) = deduce(&board);
+        assert_eq!(bombs, HashSet::from([Position::new(0, 1)]));
+        assert!(safe.is_empty());
+    }
+
+    #[test]
+    fn test_deduce_subset_subtraction() {
+        // The 1-1 pattern: the cell only the left `1` can see must be safe.
+        let board = Board {
+            states: vec![
+                vec![CellState::Empty, CellState::Danger(1), CellState::Danger(1)],
+                vec![CellState::Unknown, CellState::Unknown, CellState::Unknown],
+            ],
+            bomb_positions: HashSet::new(),
+            num_bombs: 1,
+        };
+        let (bombs, safe) = deduce(&board);
+        assert!(bombs.is_empty());
+        assert!(safe.contains(&Position::new(1, 0)));
+    }
+
+    #[test]
+    fn test_shared_cell_constraints() {
+        // Two `1`s sharing their middle unknown: either the shared cell holds
+        // the bomb or both lower corners do, so each of the three unknowns is a
+        // bomb in one of the two satisfying assignments.
+        //   1 - 1   with empties above and one empty between the lower corners
+        let board = Board {
+            states: vec![
+                vec![CellState::Empty, CellState::Empty, CellState::Empty],
+                vec![CellState::Danger(1), CellState::Unknown, CellState::Danger(1)],
+                vec![CellState::Unknown, CellState::Empty, CellState::Unknown],
+            ],
+            bomb_positions: HashSet::new(),
+            num_bombs: 2,
+        };
+        let ranked = rank_positions(&board);
+        assert_eq!(probability_at(&ranked, Position::new(1, 1)), 0.5);
+        assert_eq!(probability_at(&ranked, Position::new(2, 0)), 0.5);
+        assert_eq!(probability_at(&ranked, Position::new(2, 2)), 0.5);
+    }
 }